@@ -0,0 +1,72 @@
+//! Post-install build/prepare hooks for skills
+//!
+//! Some skills ship source that needs a preparation step (generating
+//! assets, compiling a helper) before they're usable. A skill's SKILL.md
+//! can declare a `build` (or `prepare`) list of shell commands, each
+//! templated like a malachite Dockerfile step with `{{ name }}` / `{{ path }}`
+//! placeholders, and run with the skill's checked-out directory as CWD.
+//!
+//! Commands run with everything but `PATH` stripped from the environment,
+//! so hooks can't accidentally depend on the caller's env - but `PATH`
+//! itself is kept, since real prepare steps (`npm`, `cargo`, `make`, even
+//! `sh` resolving its own builtins) need it to find their tools.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+/// Substitute `{{ name }}` / `{{ path }}` placeholders (with or without
+/// surrounding spaces) in a command template.
+fn render_template(template: &str, name: &str, path: &str) -> String {
+    template
+        .replace("{{ name }}", name)
+        .replace("{{name}}", name)
+        .replace("{{ path }}", path)
+        .replace("{{path}}", path)
+}
+
+/// Run a skill's `build` commands, in order, with `skill_dir` as the
+/// working directory and a minimal environment (just `PATH`). Stops at
+/// the first command that exits non-zero and returns an error carrying
+/// its captured stdout/stderr; callers should treat that as a failed
+/// skill, not a failed run.
+pub fn run_build_hook(commands: &[String], skill_name: &str, skill_dir: &Path) -> Result<()> {
+    let path = skill_dir.to_string_lossy().to_string();
+
+    for template in commands {
+        let command = render_template(template, skill_name, &path);
+
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(&command)
+            .current_dir(skill_dir)
+            .env_clear()
+            .env("PATH", std::env::var_os("PATH").unwrap_or_default())
+            .output()
+            .context(format!("Failed to run build command for '{}'", skill_name))?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "build command `{}` failed for '{}' ({}):\nstdout: {}\nstderr: {}",
+                command,
+                skill_name,
+                output.status,
+                String::from_utf8_lossy(&output.stdout).trim(),
+                String::from_utf8_lossy(&output.stderr).trim(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template_substitutes_name_and_path() {
+        let rendered = render_template("echo {{ name }} in {{ path }}", "pdf", "/tmp/pdf");
+        assert_eq!(rendered, "echo pdf in /tmp/pdf");
+    }
+}
@@ -0,0 +1,231 @@
+//! Persistent, content-addressed git cache
+//!
+//! Mirrors cargo's split between a "git database" (one bare mirror per
+//! source, fetched incrementally) and per-SHA checkouts materialized on
+//! demand. This means cloning the same marketplace repo for several
+//! skills, or across separate `add`/`update`/`install` invocations, reuses
+//! one local mirror that is brought up to date with an incremental fetch
+//! instead of a fresh clone, and a commit already checked out is reused
+//! as-is.
+
+use anyhow::{Context, Result};
+use git2::{Cred, FetchOptions, RemoteCallbacks};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::git::{host_of, source_token, GitSource};
+
+const LAST_ACCESS_MARKER: &str = ".agpm-last-access";
+
+/// Build fetch options for `source`: try a configured HTTPS token first
+/// (so private repos and self-hosted forges behind auth work over HTTPS),
+/// falling back to the SSH agent for `git@` remotes or hosts with no
+/// token configured.
+fn fetch_options(source: &GitSource) -> FetchOptions<'static> {
+    let token = source_token(source);
+
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(move |_url, username_from_url, allowed_types| {
+        if let Some(token) = &token {
+            if allowed_types.contains(git2::CredentialType::USER_PASS_PLAINTEXT) {
+                return Cred::userpass_plaintext(token, "x-oauth-basic");
+            }
+        }
+        Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+    });
+
+    let mut fetch_opts = FetchOptions::new();
+    fetch_opts.remote_callbacks(callbacks);
+    fetch_opts
+}
+
+/// Turn a canonical source identifier into a filesystem-safe directory name.
+fn sanitize(canonical: &str) -> String {
+    canonical
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Cache key for `source`: `canonical()` alone (`owner/repo`) collides
+/// across forges with the same owner/repo, so the host is prefixed in -
+/// otherwise `github.com/acme/repo` and `gitlab.com/acme/repo` would
+/// silently share a mirror and serve each other's commits.
+fn cache_key(source: &GitSource) -> String {
+    match host_of(&source.url) {
+        Some(host) => format!("{}/{}", host, source.canonical()),
+        None => source.canonical(),
+    }
+}
+
+fn touch_last_access(dir: &Path) {
+    let _ = std::fs::write(dir.join(LAST_ACCESS_MARKER), "");
+}
+
+fn last_access(dir: &Path) -> Option<SystemTime> {
+    std::fs::metadata(dir.join(LAST_ACCESS_MARKER))
+        .and_then(|m| m.modified())
+        .ok()
+}
+
+/// The on-disk cache of bare mirrors and SHA-keyed checkouts.
+pub struct RepoCache {
+    root: PathBuf,
+}
+
+impl RepoCache {
+    /// Open (creating if needed) the cache rooted at the platform cache dir,
+    /// e.g. `~/.cache/agpm` on Linux.
+    pub fn open() -> Result<Self> {
+        let root = dirs::cache_dir()
+            .ok_or_else(|| anyhow::anyhow!("Could not determine platform cache directory"))?
+            .join("agpm");
+        std::fs::create_dir_all(&root)
+            .context("Failed to create agpm cache directory")?;
+        Ok(Self { root })
+    }
+
+    fn mirror_dir(&self, source: &GitSource) -> PathBuf {
+        self.root.join("git").join(sanitize(&cache_key(source)))
+    }
+
+    fn checkout_dir(&self, source: &GitSource, sha: &str) -> PathBuf {
+        self.root.join("checkouts").join(sanitize(&cache_key(source))).join(sha)
+    }
+
+    /// Ensure a bare mirror of `source` exists locally, cloning it on first
+    /// use and fetching ref updates on every subsequent use.
+    pub fn ensure_mirror(&self, source: &GitSource) -> Result<PathBuf> {
+        let mirror = self.mirror_dir(source);
+
+        if mirror.join("HEAD").exists() {
+            let repo = git2::Repository::open_bare(&mirror)
+                .context("Failed to open cached mirror")?;
+            let mut remote = repo.find_remote("origin")
+                .context("Cached mirror missing 'origin' remote")?;
+            remote
+                .fetch(
+                    &["+refs/heads/*:refs/heads/*", "+refs/tags/*:refs/tags/*"],
+                    Some(&mut fetch_options(source)),
+                    None,
+                )
+                .context(format!("Failed to fetch updates for {}", source.url))?;
+        } else {
+            if let Some(parent) = mirror.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut builder = git2::build::RepoBuilder::new();
+            builder.bare(true);
+            builder.fetch_options(fetch_options(source));
+            builder
+                .clone(&source.url, &mirror)
+                .context(format!("Failed to clone {} into cache", source.url))?;
+        }
+
+        touch_last_access(&mirror);
+        Ok(mirror)
+    }
+
+    /// Resolve `source.ref_` (or the default branch) to a commit SHA using
+    /// the cached mirror, without touching the network beyond the fetch
+    /// already performed by `ensure_mirror`.
+    pub fn resolve_local_sha(&self, source: &GitSource, mirror: &Path) -> Result<String> {
+        let repo = git2::Repository::open_bare(mirror)
+            .context("Failed to open cached mirror")?;
+
+        let commit = match &source.ref_ {
+            Some(ref_) => repo
+                .revparse_single(ref_)
+                .or_else(|_| repo.revparse_single(&format!("origin/{}", ref_)))
+                .context(format!("Ref '{}' not found in {}", ref_, source.url))?
+                .peel_to_commit()?,
+            None => repo.head()
+                .context("Cached mirror has no HEAD")?
+                .peel_to_commit()
+                .context("Failed to peel HEAD to commit")?,
+        };
+
+        Ok(commit.id().to_string())
+    }
+
+    /// Return the path to an already-materialized checkout for `sha`
+    /// without touching the network, or `None` if it hasn't been checked
+    /// out yet. Lets callers that already know their SHA skip the mirror
+    /// fetch entirely (e.g. a `--frozen` or offline install).
+    pub fn cached_checkout(&self, source: &GitSource, sha: &str) -> Option<PathBuf> {
+        let dest = self.checkout_dir(source, sha);
+        if dest.join(".git").exists() {
+            touch_last_access(&dest);
+            Some(dest)
+        } else {
+            None
+        }
+    }
+
+    /// Materialize a worktree for `sha`, reusing it if it was
+    /// already checked out by a previous call.
+    pub fn checkout(&self, source: &GitSource, mirror: &Path, sha: &str) -> Result<PathBuf> {
+        let dest = self.checkout_dir(source, sha);
+
+        if dest.join(".git").exists() {
+            touch_last_access(&dest);
+            return Ok(dest);
+        }
+
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let repo = git2::build::RepoBuilder::new()
+            .clone(mirror.to_str().unwrap(), &dest)
+            .context("Failed to materialize cached checkout")?;
+
+        let oid = git2::Oid::from_str(sha)
+            .context(format!("Invalid SHA '{}'", sha))?;
+        let commit = repo.find_commit(oid)
+            .context(format!("SHA '{}' not present in cached mirror", sha))?;
+
+        repo.set_head_detached(commit.id())?;
+        let mut checkout_opts = git2::build::CheckoutBuilder::new();
+        checkout_opts.force();
+        repo.checkout_head(Some(&mut checkout_opts))
+            .context("Failed to check out cached SHA")?;
+
+        touch_last_access(&dest);
+        Ok(dest)
+    }
+
+    /// Remove checkouts that haven't been accessed within `max_age`.
+    /// Bare mirrors are left alone since they're cheap to keep and expensive
+    /// to rebuild. Returns the number of checkouts removed.
+    pub fn gc(&self, max_age: Duration) -> Result<usize> {
+        let checkouts_root = self.root.join("checkouts");
+        if !checkouts_root.exists() {
+            return Ok(0);
+        }
+
+        let now = SystemTime::now();
+        let mut removed = 0;
+
+        for source_entry in std::fs::read_dir(&checkouts_root)? {
+            let source_dir = source_entry?.path();
+            if !source_dir.is_dir() {
+                continue;
+            }
+            for sha_entry in std::fs::read_dir(&source_dir)? {
+                let sha_dir = sha_entry?.path();
+                let stale = match last_access(&sha_dir) {
+                    Some(accessed) => now.duration_since(accessed).unwrap_or_default() > max_age,
+                    None => true,
+                };
+                if stale {
+                    std::fs::remove_dir_all(&sha_dir)
+                        .context(format!("Failed to remove stale checkout {}", sha_dir.display()))?;
+                    removed += 1;
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+}
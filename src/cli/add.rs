@@ -1,10 +1,13 @@
 //! Add command - add a skill to skills.json
 
 use anyhow::Result;
+use std::io::IsTerminal;
 
 use crate::config::{SkillsConfig, SkillSpec};
 use crate::git::{GitSource, ClonedRepo};
 use crate::skill::discover_skills;
+use crate::suggest::not_found_message;
+use crate::tui::picker::{multi_select, PickerItem};
 
 pub async fn run(source: &str, skill_name: Option<&str>) -> Result<()> {
     let cwd = std::env::current_dir()?;
@@ -30,21 +33,37 @@ pub async fn run(source: &str, skill_name: Option<&str>) -> Result<()> {
 
     // Select skill(s) to add
     let selected_skills: Vec<_> = if let Some(name) = skill_name {
-        skills.into_iter()
+        let found: Vec<_> = skills.iter()
             .filter(|s| s.metadata.name == name)
-            .collect()
+            .cloned()
+            .collect();
+        if found.is_empty() {
+            let candidates = skills.iter().map(|s| s.metadata.name.as_str());
+            anyhow::bail!(not_found_message("Skill", name, candidates));
+        }
+        found
     } else if skills.len() == 1 {
         skills
+    } else if std::io::stdout().is_terminal() {
+        let items: Vec<PickerItem> = skills.iter()
+            .map(|s| PickerItem {
+                name: s.metadata.name.clone(),
+                description: s.metadata.description.clone(),
+            })
+            .collect();
+
+        let chosen = multi_select(&items)?;
+        if chosen.is_empty() {
+            println!("\nNo skills selected.");
+            return Ok(());
+        }
+
+        chosen.into_iter().map(|i| skills[i].clone()).collect()
     } else {
-        // TODO: Interactive selection with TUI
         println!("\nMultiple skills found. Use --skill <name> to select one.");
         return Ok(());
     };
 
-    if selected_skills.is_empty() {
-        anyhow::bail!("Skill '{}' not found", skill_name.unwrap_or(""));
-    }
-
     // Load or create config
     let mut config = SkillsConfig::load(&cwd).await?;
     
@@ -0,0 +1,20 @@
+//! Cache command - maintain the local content-addressed git cache
+
+use anyhow::Result;
+use std::time::Duration;
+
+use crate::cache::RepoCache;
+use crate::cli::CacheCommands;
+
+pub async fn run(action: CacheCommands) -> Result<()> {
+    match action {
+        CacheCommands::Clean { days } => {
+            let cache = RepoCache::open()?;
+            let max_age = Duration::from_secs(days * 24 * 60 * 60);
+            let removed = cache.gc(max_age)?;
+            println!("Removed {} stale checkout(s) older than {} day(s).", removed, days);
+        }
+    }
+
+    Ok(())
+}
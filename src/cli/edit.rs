@@ -0,0 +1,44 @@
+//! Edit command - open skills.json in $EDITOR for bulk changes
+
+use anyhow::{Context, Result};
+use std::process::Command;
+
+use crate::config::SkillsConfig;
+
+pub async fn run() -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let path = cwd.join(SkillsConfig::FILENAME);
+
+    if !path.exists() {
+        SkillsConfig::default().save(&cwd).await?;
+    }
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    loop {
+        let status = Command::new(&editor)
+            .arg(&path)
+            .status()
+            .context(format!("Failed to launch editor '{}'", editor))?;
+
+        if !status.success() {
+            anyhow::bail!("Editor '{}' exited with an error", editor);
+        }
+
+        let content = tokio::fs::read_to_string(&path).await
+            .context(format!("Failed to read {}", SkillsConfig::FILENAME))?;
+
+        match serde_json::from_str::<SkillsConfig>(&content) {
+            Ok(_) => {
+                println!("{} is valid.", SkillsConfig::FILENAME);
+                return Ok(());
+            }
+            Err(e) => {
+                println!("Failed to parse {}: {}", SkillsConfig::FILENAME, e);
+                println!("Reopening the editor so you can fix it (your changes are still on disk)...");
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Index command - generate skills-index.json for a marketplace repo
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+use crate::skill::{discover_skills, SkillIndexEntry, SkillsIndex, SKILLS_INDEX_FILENAME};
+
+pub async fn run(path: Option<&str>) -> Result<()> {
+    let base = match path {
+        Some(p) => PathBuf::from(p),
+        None => std::env::current_dir()?,
+    };
+
+    let sha = current_head_sha(&base).unwrap_or_default();
+    let skills = discover_skills(&base, None).await?;
+
+    if skills.is_empty() {
+        anyhow::bail!("No skills found under {}", base.display());
+    }
+
+    let index = SkillsIndex {
+        skills: skills.iter()
+            .map(|skill| SkillIndexEntry {
+                name: skill.metadata.name.clone(),
+                description: skill.metadata.description.clone(),
+                path: skill.relative_path.clone(),
+                sha: sha.clone(),
+            })
+            .collect(),
+    };
+
+    let content = serde_json::to_string_pretty(&index)?;
+    let out_path = base.join(SKILLS_INDEX_FILENAME);
+    tokio::fs::write(&out_path, content).await
+        .context(format!("Failed to write {}", SKILLS_INDEX_FILENAME))?;
+
+    println!("Wrote {} skill(s) to {}", index.skills.len(), out_path.display());
+
+    Ok(())
+}
+
+/// Best-effort HEAD SHA for the repo being indexed, empty if `base` isn't
+/// a git checkout.
+fn current_head_sha(base: &std::path::Path) -> Option<String> {
+    let repo = git2::Repository::discover(base).ok()?;
+    let head = repo.head().ok()?;
+    Some(head.peel_to_commit().ok()?.id().to_string())
+}
@@ -1,158 +1,359 @@
 //! Install command - install skills from lock file or resolve from skills.json
 
 use anyhow::{Result, Context};
-use std::path::PathBuf;
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
-use crate::config::{SkillsConfig, SkillsLock, LockedSkill};
+use crate::config::{expand_agent_path, SkillsConfig, SkillsLock, LockedSkill};
 use crate::git::{GitSource, ClonedRepo};
 use crate::skill::discover_skills;
 
-/// Target agents and their skill directories
-const AGENTS: &[(&str, &str)] = &[
-    ("claude-code", ".claude/skills"),
-    ("opencode", ".opencode/skills"),
-    ("cursor", ".cursor/skills"),
-    ("codex", ".codex/skills"),
-];
+/// Default number of sources resolved/cloned concurrently.
+const DEFAULT_JOBS: usize = 8;
+
+/// One skill to install, grouped later by its source so a marketplace with
+/// many enabled skills only resolves and clones it once.
+struct InstallTarget {
+    skill_name: String,
+    source: String,
+    path: Option<String>,
+    marketplace: Option<String>,
+    /// Pinned ref from `skills.json` (`owner/repo@ref`), if any.
+    ref_: Option<String>,
+    locked_sha: Option<String>,
+    locked_built: bool,
+}
+
+enum TargetResult {
+    Installed { name: String, locked_skill: LockedSkill, was_locked: bool },
+    Failed { name: String, error: String },
+}
+
+/// How strictly install must stick to `skills-lock.json`, mirroring
+/// cargo's `--locked`/`--frozen`.
+#[derive(Clone, Copy, PartialEq)]
+enum InstallMode {
+    /// Default: reuse a source's locked SHA when all of its skills agree on
+    /// one, otherwise resolve the source and relock its skills.
+    Normal,
+    /// Every configured skill must already have a lock entry; install
+    /// exactly that SHA rather than re-resolving.
+    Locked,
+    /// `Locked`, plus never touch the network: a locked SHA not already
+    /// in the local cache is a hard failure instead of a fetch.
+    Frozen,
+}
+
+pub async fn run(locked: bool, frozen: bool) -> Result<()> {
+    let mode = if frozen {
+        InstallMode::Frozen
+    } else if locked {
+        InstallMode::Locked
+    } else {
+        InstallMode::Normal
+    };
 
-pub async fn run() -> Result<()> {
     let cwd = std::env::current_dir()?;
-    
+
     let config = SkillsConfig::load(&cwd).await?;
     let mut lock = SkillsLock::load(&cwd).await?;
-    
+
     if config.skills.is_empty() && config.marketplaces.is_empty() {
         println!("No skills configured in skills.json");
         println!("Run 'skills add <source>' to add skills.");
         return Ok(());
     }
 
-    let mut installed_count = 0;
+    let mut targets: Vec<InstallTarget> = Vec::new();
 
-    // Install individual skills
     for skill_spec in &config.skills {
-        println!("\nProcessing skill: {}", skill_spec.name);
-        
-        // Check if we have a lock entry
         let locked = lock.skills.get(&skill_spec.name);
-        
-        // Clone the repo (keep it alive until we're done copying)
-        let git_source = if let Some(locked) = locked {
-            println!("  Using locked SHA: {}", &locked.sha[..8]);
-            GitSource::parse(&locked.source)?
-        } else {
-            println!("  Resolving from source: {}", skill_spec.source);
-            GitSource::parse(&skill_spec.source)?
-        };
-        
-        let cloned = ClonedRepo::clone(&git_source)?;
-        let sha = cloned.sha.clone();
-        
-        // Find the skill in the cloned repo
-        let skills = discover_skills(&cloned.path, skill_spec.path.as_deref()).await?;
-        let skill = skills.into_iter()
-            .find(|s| s.metadata.name == skill_spec.name)
-            .ok_or_else(|| anyhow::anyhow!("Skill '{}' not found in {}", skill_spec.name, skill_spec.source))?;
-        
-        // Update lock file
-        let locked_skill = LockedSkill {
-            name: skill.metadata.name.clone(),
-            source: git_source.url.clone(),
-            sha: sha.clone(),
-            path: skill.relative_path.clone(),
-            description: Some(skill.metadata.description.clone()),
+        targets.push(InstallTarget {
+            skill_name: skill_spec.name.clone(),
+            source: skill_spec.source.clone(),
+            path: skill_spec.path.clone(),
             marketplace: None,
-        };
-        lock.skills.insert(skill_spec.name.clone(), locked_skill);
-        
-        // Install to agent directories (cloned repo is still alive here)
-        install_skill_to_agents(&skill_spec.name, &skill.path, &cwd).await?;
-        installed_count += 1;
-        
-        println!("  Installed: {} @ {}", skill_spec.name, &sha[..8]);
+            ref_: skill_spec.ref_.clone(),
+            locked_sha: locked.map(|l| l.sha.clone()),
+            locked_built: locked.is_some_and(|l| l.built),
+        });
     }
 
-    // Install marketplace skills
     for marketplace in &config.marketplaces {
-        if marketplace.enabled.is_empty() {
-            continue;
-        }
-        
-        println!("\nProcessing marketplace: {}", marketplace.name);
-        
-        let git_source = GitSource::parse(&marketplace.source)?;
-        let cloned = ClonedRepo::clone(&git_source)?;
-        
-        let skills = discover_skills(&cloned.path, None).await?;
-        
-        for skill_name in &marketplace.enabled {
-            let skill = skills.iter()
-                .find(|s| &s.metadata.name == skill_name)
-                .ok_or_else(|| anyhow::anyhow!(
-                    "Skill '{}' not found in marketplace '{}'", 
-                    skill_name, marketplace.name
-                ))?;
-            
-            // Update lock
-            let locked_skill = LockedSkill {
-                name: skill.metadata.name.clone(),
-                source: git_source.url.clone(),
-                sha: cloned.sha.clone(),
-                path: skill.relative_path.clone(),
-                description: Some(skill.metadata.description.clone()),
+        for enabled in &marketplace.enabled {
+            let locked = lock.skills.get(enabled);
+            targets.push(InstallTarget {
+                skill_name: enabled.clone(),
+                source: marketplace.source.clone(),
+                path: None,
                 marketplace: Some(marketplace.name.clone()),
-            };
-            lock.skills.insert(skill_name.clone(), locked_skill);
-            
-            install_skill_to_agents(skill_name, &skill.path, &cwd).await?;
-            installed_count += 1;
-            
-            println!("  Installed: {} @ {}", skill_name, &cloned.sha[..8]);
+                ref_: marketplace.ref_.clone(),
+                locked_sha: locked.map(|l| l.sha.clone()),
+                locked_built: locked.is_some_and(|l| l.built),
+            });
+        }
+    }
+
+    // Reconcile: skills.json plus enabled marketplace entries is the
+    // desired state. Anything still tracked in the lock but no longer
+    // desired (removed from skills.json, disabled in a marketplace) is
+    // pruned from every agent directory and dropped from the lock, so
+    // `install` alone keeps things in sync without an explicit `remove`.
+    // Only names already tracked in the lock are ever touched, so unrelated
+    // directories a user drops under `.claude/skills` etc. are left alone.
+    let desired: std::collections::HashSet<&str> =
+        targets.iter().map(|t| t.skill_name.as_str()).collect();
+    let stale: Vec<String> = lock.skills.keys()
+        .filter(|name| !desired.contains(name.as_str()))
+        .cloned()
+        .collect();
+
+    // `--locked`/`--frozen` promise a deterministic, auditable install
+    // that matches the lock file exactly - pruning stale entries would
+    // mutate the lock itself, which defeats that guarantee. Surface it as
+    // a hard error instead of silently pruning (or silently not pruning).
+    if mode != InstallMode::Normal && !stale.is_empty() {
+        anyhow::bail!(
+            "lock file has {} stale skill(s) ({}) no longer in skills.json; run 'skills install' without --locked/--frozen first",
+            stale.len(),
+            stale.join(", "),
+        );
+    }
+
+    let agents = config.agent_targets();
+
+    for name in &stale {
+        for (agent_name, template) in &agents {
+            let path = cwd.join(expand_agent_path(template, name));
+            if path.exists() {
+                tokio::fs::remove_dir_all(&path).await
+                    .context(format!("Failed to remove stale skill '{}' from {}", name, agent_name))?;
+            }
         }
+        lock.skills.remove(name);
+        println!("{}: removed (no longer in skills.json)", name);
+    }
+
+    // Group targets sharing a source so each distinct GitSource is only
+    // resolved and cloned once, no matter how many skills come from it.
+    let mut groups: HashMap<String, Vec<InstallTarget>> = HashMap::new();
+    for target in targets {
+        groups.entry(target.source.clone()).or_default().push(target);
     }
 
-    // Save lock file
+    let results: Vec<Vec<TargetResult>> = stream::iter(groups.into_values())
+        .map(|group| install_group(group, cwd.clone(), agents.clone(), mode))
+        .buffer_unordered(DEFAULT_JOBS)
+        .collect()
+        .await;
+
+    let mut installed_count = 0;
+    let mut unchanged_count = 0;
+    let mut failed_count = 0;
+    let mut summary: Vec<(String, String)> = Vec::new();
+
+    for group_result in results {
+        for result in group_result {
+            match result {
+                TargetResult::Installed { name, locked_skill, was_locked } => {
+                    let line = if was_locked {
+                        unchanged_count += 1;
+                        format!("{}: unchanged @ {}", name, &locked_skill.sha[..8])
+                    } else {
+                        installed_count += 1;
+                        format!("{}: installed @ {}", name, &locked_skill.sha[..8])
+                    };
+                    summary.push((name.clone(), line));
+                    lock.skills.insert(name, locked_skill);
+                }
+                TargetResult::Failed { name, error } => {
+                    failed_count += 1;
+                    summary.push((name.clone(), format!("{}: failed - {}", name, error)));
+                }
+            }
+        }
+    }
+
+    summary.sort_by(|a, b| a.0.cmp(&b.0));
+    for (_, line) in &summary {
+        println!("{}", line);
+    }
+
+    // Lockfile writes are serialized here, after every concurrent task has
+    // finished, so nothing races on `SkillsLock`.
     lock.save(&cwd).await?;
-    
-    println!("\n{} skill(s) installed.", installed_count);
-    println!("Lock file updated: skills-lock.json");
-    
+
+    println!(
+        "\n{} installed, {} unchanged, {} removed, {} failed.",
+        installed_count, unchanged_count, stale.len(), failed_count
+    );
+
+    if failed_count > 0 {
+        anyhow::bail!("{} skill(s) failed to install", failed_count);
+    }
+
     Ok(())
 }
 
-async fn install_skill_to_agents(name: &str, source_path: &PathBuf, cwd: &PathBuf) -> Result<()> {
-    for (agent_name, skills_dir) in AGENTS {
-        let target_dir = cwd.join(skills_dir).join(name);
-        
+/// Resolve (and, if needed, clone) one distinct source, then install every
+/// target skill that came from it. A clone or resolve failure fails every
+/// target in the group; a per-skill failure (not found, copy error) only
+/// fails that skill.
+async fn install_group(
+    group: Vec<InstallTarget>,
+    cwd: PathBuf,
+    agents: Vec<(String, String)>,
+    mode: InstallMode,
+) -> Vec<TargetResult> {
+    let mut git_source = match GitSource::parse(&group[0].source) {
+        Ok(source) => source,
+        Err(e) => return fail_all(group, &e.to_string()),
+    };
+    // A pin from skills.json (`owner/repo@ref`) always wins over whatever
+    // `GitSource::parse` inferred from the bare source string.
+    if let Some(ref_) = &group[0].ref_ {
+        git_source.ref_ = Some(ref_.clone());
+    }
+
+    // If every target already agrees on a locked SHA, check that SHA out
+    // directly (offline-friendly, no re-resolution against the source).
+    let shared_locked_sha = group.iter()
+        .map(|t| t.locked_sha.as_deref())
+        .reduce(|a, b| if a == b { a } else { None })
+        .flatten();
+
+    if mode != InstallMode::Normal && shared_locked_sha.is_none() {
+        let unlocked: Vec<&str> = group.iter()
+            .filter(|t| t.locked_sha.is_none())
+            .map(|t| t.skill_name.as_str())
+            .collect();
+        let reason = if unlocked.is_empty() {
+            // Every target has a lock entry, but they disagree (e.g. two
+            // skills from the same repo pinned to different refs) - with
+            // `--locked` there's no single SHA to check out without
+            // re-resolving, which is exactly what the flag forbids.
+            "lock entries for this source disagree on a SHA".to_string()
+        } else {
+            format!("no lock entry for {} (run 'skills install' without --locked first)", unlocked.join(", "))
+        };
+        return fail_all(group, &reason);
+    }
+
+    let cloned = match (mode, shared_locked_sha) {
+        (InstallMode::Frozen, Some(sha)) => ClonedRepo::at_sha_frozen(&git_source, sha),
+        (_, Some(sha)) => ClonedRepo::at_sha(&git_source, sha),
+        (InstallMode::Normal, None) => ClonedRepo::clone(&git_source),
+        (_, None) => unreachable!("locked/frozen with no shared SHA already failed above"),
+    };
+    let cloned = match cloned {
+        Ok(cloned) => cloned,
+        Err(e) => return fail_all(group, &e.to_string()),
+    };
+
+    let was_locked = shared_locked_sha.is_some();
+    let mut results = Vec::with_capacity(group.len());
+    for target in &group {
+        let result = install_target(target, &git_source, &cloned, &cwd, &agents).await;
+        results.push(match result {
+            Ok(locked_skill) => TargetResult::Installed {
+                name: target.skill_name.clone(),
+                locked_skill,
+                was_locked,
+            },
+            Err(e) => TargetResult::Failed {
+                name: target.skill_name.clone(),
+                error: e.to_string(),
+            },
+        });
+    }
+    results
+}
+
+fn fail_all(group: Vec<InstallTarget>, error: &str) -> Vec<TargetResult> {
+    group.into_iter()
+        .map(|t| TargetResult::Failed { name: t.skill_name, error: error.to_string() })
+        .collect()
+}
+
+async fn install_target(
+    target: &InstallTarget,
+    git_source: &GitSource,
+    cloned: &ClonedRepo,
+    cwd: &Path,
+    agents: &[(String, String)],
+) -> Result<LockedSkill> {
+    let skills = discover_skills(&cloned.path, target.path.as_deref()).await?;
+    let skill = skills.into_iter()
+        .find(|s| s.metadata.name == target.skill_name)
+        .ok_or_else(|| anyhow::anyhow!("Skill '{}' not found in {}", target.skill_name, target.source))?;
+
+    // Build before copying to agent directories: a `build` hook generates
+    // assets/helpers into `skill.path` (the cache checkout), and those
+    // need to exist before `install_skill_to_agents` copies the tree out,
+    // or they'd never make it into `.claude/skills/...` and friends.
+    let sha_unchanged = target.locked_sha.as_deref() == Some(cloned.sha.as_str());
+    let built = if skill.metadata.build.is_empty() {
+        false
+    } else if sha_unchanged && target.locked_built {
+        true
+    } else {
+        crate::build::run_build_hook(&skill.metadata.build, &target.skill_name, &skill.path)?;
+        true
+    };
+
+    install_skill_to_agents(&target.skill_name, &skill.path, cwd, agents).await?;
+
+    Ok(LockedSkill {
+        name: skill.metadata.name.clone(),
+        source: git_source.url.clone(),
+        sha: cloned.sha.clone(),
+        ref_: target.ref_.clone(),
+        path: skill.relative_path.clone(),
+        description: Some(skill.metadata.description.clone()),
+        marketplace: target.marketplace.clone(),
+        built,
+    })
+}
+
+async fn install_skill_to_agents(
+    name: &str,
+    source_path: &PathBuf,
+    cwd: &Path,
+    agents: &[(String, String)],
+) -> Result<()> {
+    for (agent_name, template) in agents {
+        let target_dir = cwd.join(expand_agent_path(template, name));
+
         // Create parent directory
         if let Some(parent) = target_dir.parent() {
             tokio::fs::create_dir_all(parent).await
                 .context(format!("Failed to create {} directory", agent_name))?;
         }
-        
+
         // Copy skill directory
         copy_dir_recursive(source_path, &target_dir).await
             .context(format!("Failed to copy skill to {}", agent_name))?;
     }
-    
+
     Ok(())
 }
 
 async fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<()> {
     tokio::fs::create_dir_all(dst).await?;
-    
+
     let mut entries = tokio::fs::read_dir(src).await?;
     while let Some(entry) = entries.next_entry().await? {
         let path = entry.path();
         let file_name = path.file_name().unwrap();
         let dst_path = dst.join(file_name);
-        
+
         if path.is_dir() {
             Box::pin(copy_dir_recursive(&path, &dst_path)).await?;
         } else {
             tokio::fs::copy(&path, &dst_path).await?;
         }
     }
-    
+
     Ok(())
 }
@@ -1,4 +1,7 @@
 pub mod add;
+pub mod cache;
+pub mod edit;
+pub mod index;
 pub mod install;
 pub mod list;
 pub mod remove;
@@ -25,11 +28,28 @@ pub enum Commands {
         skill: Option<String>,
     },
     /// Install skills from skills-lock.json (or resolve from skills.json)
-    Install,
+    Install {
+        /// Require every configured skill to already have a lock entry and
+        /// install exactly that SHA, erroring instead of re-resolving or
+        /// updating the lock
+        #[arg(long)]
+        locked: bool,
+        /// Like --locked, and additionally forbid any network access:
+        /// fails instead of fetching if a locked commit isn't already cached
+        #[arg(long)]
+        frozen: bool,
+    },
     /// Update skills to latest versions (updates lock file)
     Update {
-        /// Specific skill to update (updates all if not specified)
-        skill: Option<String>,
+        /// Specific skills to update (updates all if none given)
+        skills: Vec<String>,
+        /// Skip a skill even if it's targeted by `skills` or by default;
+        /// repeatable
+        #[arg(long = "exclude")]
+        exclude: Vec<String>,
+        /// Maximum number of sources to resolve/clone concurrently
+        #[arg(short = 'j', long = "jobs")]
+        jobs: Option<usize>,
     },
     /// List installed skills
     List,
@@ -38,4 +58,26 @@ pub enum Commands {
         /// Skill name to remove
         skill: String,
     },
+    /// Manage the local git cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheCommands,
+    },
+    /// Generate a skills-index.json for a marketplace repo
+    Index {
+        /// Repo directory to scan (defaults to the current directory)
+        path: Option<String>,
+    },
+    /// Open skills.json in $EDITOR for bulk edits
+    Edit,
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// Remove cached checkouts that haven't been accessed recently
+    Clean {
+        /// Remove checkouts not accessed within this many days
+        #[arg(long, default_value_t = 30)]
+        days: u64,
+    },
 }
@@ -2,22 +2,22 @@
 
 use anyhow::Result;
 
-use crate::config::{SkillsConfig, SkillsLock};
-
-/// Target agents and their skill directories  
-const AGENTS: &[(&str, &str)] = &[
-    ("claude-code", ".claude/skills"),
-    ("opencode", ".opencode/skills"),
-    ("cursor", ".cursor/skills"),
-    ("codex", ".codex/skills"),
-];
+use crate::config::{expand_agent_path, SkillsConfig, SkillsLock};
+use crate::suggest::not_found_message;
 
 pub async fn run(skill_name: &str) -> Result<()> {
     let cwd = std::env::current_dir()?;
     
     let mut config = SkillsConfig::load(&cwd).await?;
     let mut lock = SkillsLock::load(&cwd).await?;
-    
+
+    let known_names: Vec<&str> = config.skills.iter().map(|s| s.name.as_str())
+        .chain(config.marketplaces.iter().flat_map(|m| m.enabled.iter().map(|s| s.as_str())))
+        .collect();
+    if !known_names.contains(&skill_name) {
+        anyhow::bail!(not_found_message("Skill", skill_name, known_names));
+    }
+
     let mut found = false;
 
     // Remove from individual skills
@@ -49,8 +49,8 @@ pub async fn run(skill_name: &str) -> Result<()> {
     }
 
     // Remove installed files
-    for (agent_name, skills_dir) in AGENTS {
-        let skill_path = cwd.join(skills_dir).join(skill_name);
+    for (agent_name, template) in config.agent_targets() {
+        let skill_path = cwd.join(expand_agent_path(&template, skill_name));
         if skill_path.exists() {
             tokio::fs::remove_dir_all(&skill_path).await?;
             println!("Removed {} from {}", skill_name, agent_name);
@@ -1,118 +1,266 @@
 //! Update command - update skills to latest versions
 
 use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
 
-use crate::config::{SkillsConfig, SkillsLock, LockedSkill};
+use crate::config::{Marketplace, SkillsConfig, SkillsLock, LockedMarketplace, LockedSkill};
 use crate::git::{GitSource, ClonedRepo, resolve_sha};
-use crate::skill::discover_skills;
+use crate::skill::{discover_skills, fetch_remote_index};
+use crate::suggest::not_found_message;
 
-pub async fn run(skill_name: Option<&str>) -> Result<()> {
+/// Default number of sources resolved/cloned concurrently when `--jobs`
+/// isn't given.
+const DEFAULT_JOBS: usize = 8;
+
+/// One skill that's a candidate for updating, grouped later by its source
+/// so a marketplace with many enabled skills only resolves once.
+struct UpdateTarget {
+    skill_name: String,
+    source: String,
+    path: Option<String>,
+    marketplace: Option<String>,
+    /// Pinned ref from `skills.json` (`owner/repo@ref`), if any. Re-resolved
+    /// against this ref rather than the default branch.
+    ref_: Option<String>,
+}
+
+/// Work out which configured skill names `update` should touch: an
+/// explicit `skills` list (validated against `known_names`) if given,
+/// otherwise every known skill, minus anything in `exclude`. Shared
+/// between the CLI and `App::update_skills` so a future TUI multi-select
+/// can drive the exact same set computation.
+pub fn resolve_update_targets(
+    known_names: &[String],
+    skills: &[String],
+    exclude: &[String],
+) -> Result<std::collections::HashSet<String>> {
+    let known: Vec<&str> = known_names.iter().map(|s| s.as_str()).collect();
+
+    for name in skills.iter().chain(exclude) {
+        if !known.contains(&name.as_str()) {
+            anyhow::bail!(not_found_message("Skill", name, known.clone()));
+        }
+    }
+
+    let base: std::collections::HashSet<String> = if skills.is_empty() {
+        known_names.iter().cloned().collect()
+    } else {
+        skills.iter().cloned().collect()
+    };
+
+    Ok(base.into_iter().filter(|name| !exclude.contains(name)).collect())
+}
+
+pub async fn run(skills: &[String], exclude: &[String], jobs: Option<usize>) -> Result<()> {
     let cwd = std::env::current_dir()?;
-    
+
     let config = SkillsConfig::load(&cwd).await?;
     let mut lock = SkillsLock::load(&cwd).await?;
-    
+
     if config.skills.is_empty() && config.marketplaces.is_empty() {
         println!("No skills configured in skills.json");
         return Ok(());
     }
 
-    let mut updated_count = 0;
+    let known_names: Vec<String> = config.skills.iter().map(|s| s.name.clone())
+        .chain(config.marketplaces.iter().flat_map(|m| m.enabled.iter().cloned()))
+        .collect();
+    let wanted = resolve_update_targets(&known_names, skills, exclude)?;
+
+    let mut targets: Vec<UpdateTarget> = Vec::new();
 
-    // Update individual skills
     for skill_spec in &config.skills {
-        if let Some(name) = skill_name {
-            if skill_spec.name != name {
-                continue;
-            }
-        }
-        
-        println!("Checking {}", skill_spec.name);
-        
-        let git_source = GitSource::parse(&skill_spec.source)?;
-        let new_sha = resolve_sha(&git_source).await?;
-        
-        let current_sha = lock.skills.get(&skill_spec.name).map(|s| s.sha.as_str());
-        
-        if current_sha == Some(&new_sha) {
-            println!("  Already up to date: {}", &new_sha[..8]);
+        if !wanted.contains(&skill_spec.name) {
             continue;
         }
-        
-        println!("  Updating {} -> {}", 
-            current_sha.map(|s| &s[..8]).unwrap_or("none"),
-            &new_sha[..8]
-        );
-        
-        // Clone and discover skill
-        let cloned = ClonedRepo::clone(&git_source)?;
-        let skills = discover_skills(&cloned.path, skill_spec.path.as_deref()).await?;
-        
-        let skill = skills.into_iter()
-            .find(|s| s.metadata.name == skill_spec.name)
-            .ok_or_else(|| anyhow::anyhow!("Skill '{}' not found", skill_spec.name))?;
-        
-        // Update lock
-        let locked_skill = LockedSkill {
-            name: skill.metadata.name.clone(),
-            source: git_source.url.clone(),
-            sha: new_sha.clone(),
-            path: skill.relative_path.clone(),
-            description: Some(skill.metadata.description.clone()),
+        targets.push(UpdateTarget {
+            skill_name: skill_spec.name.clone(),
+            source: skill_spec.source.clone(),
+            path: skill_spec.path.clone(),
             marketplace: None,
-        };
-        lock.skills.insert(skill_spec.name.clone(), locked_skill);
-        updated_count += 1;
+            ref_: skill_spec.ref_.clone(),
+        });
     }
 
-    // Update marketplace skills
     for marketplace in &config.marketplaces {
-        let git_source = GitSource::parse(&marketplace.source)?;
-        let new_sha = resolve_sha(&git_source).await?;
-        
-        for skill_name_to_check in &marketplace.enabled {
-            if let Some(name) = skill_name {
-                if skill_name_to_check != name {
-                    continue;
-                }
-            }
-            
-            let current_sha = lock.skills.get(skill_name_to_check).map(|s| s.sha.as_str());
-            
-            if current_sha == Some(&new_sha) {
+        for enabled in &marketplace.enabled {
+            if !wanted.contains(enabled) {
                 continue;
             }
-            
-            println!("Updating {} (marketplace: {})", skill_name_to_check, marketplace.name);
-            
-            let cloned = ClonedRepo::clone(&git_source)?;
-            let skills = discover_skills(&cloned.path, None).await?;
-            
-            let skill = skills.iter()
-                .find(|s| &s.metadata.name == skill_name_to_check)
-                .ok_or_else(|| anyhow::anyhow!("Skill '{}' not found", skill_name_to_check))?;
-            
-            let locked_skill = LockedSkill {
-                name: skill.metadata.name.clone(),
-                source: git_source.url.clone(),
-                sha: new_sha.clone(),
-                path: skill.relative_path.clone(),
-                description: Some(skill.metadata.description.clone()),
+            targets.push(UpdateTarget {
+                skill_name: enabled.clone(),
+                source: marketplace.source.clone(),
+                path: None,
                 marketplace: Some(marketplace.name.clone()),
-            };
-            lock.skills.insert(skill_name_to_check.clone(), locked_skill);
-            updated_count += 1;
+                ref_: marketplace.ref_.clone(),
+            });
+        }
+    }
+
+    // Group targets sharing a source so each distinct GitSource is only
+    // resolved and cloned once, no matter how many skills come from it.
+    let mut groups: HashMap<String, Vec<UpdateTarget>> = HashMap::new();
+    for target in targets {
+        groups.entry(target.source.clone()).or_default().push(target);
+    }
+
+    let concurrency = jobs.unwrap_or(DEFAULT_JOBS).max(1);
+    let current_shas: HashMap<String, String> = lock.skills.iter()
+        .map(|(name, locked)| (name.clone(), locked.sha.clone()))
+        .collect();
+
+    let results: Vec<Result<Vec<(String, Option<LockedSkill>)>>> = stream::iter(groups.into_values())
+        .map(|group| resolve_group(group, &current_shas))
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+
+    let mut status_lines: Vec<(String, String)> = Vec::new();
+    let mut updated_count = 0;
+
+    for result in results {
+        let entries = result?;
+        for (skill_name, locked) in entries {
+            match locked {
+                Some(locked_skill) => {
+                    let old_sha = lock.skills.get(&skill_name).map(|s| s.sha[..8].to_string());
+                    status_lines.push((
+                        skill_name.clone(),
+                        format!(
+                            "{}: {} -> {}",
+                            skill_name,
+                            old_sha.as_deref().unwrap_or("none"),
+                            &locked_skill.sha[..8],
+                        ),
+                    ));
+                    lock.skills.insert(skill_name, locked_skill);
+                    updated_count += 1;
+                }
+                None => {
+                    let sha = lock.skills.get(&skill_name).map(|s| s.sha[..8].to_string());
+                    status_lines.push((
+                        skill_name.clone(),
+                        format!("{}: already up to date ({})", skill_name, sha.as_deref().unwrap_or("none")),
+                    ));
+                }
+            }
+        }
+    }
+
+    status_lines.sort_by(|a, b| a.0.cmp(&b.0));
+    for (_, status) in &status_lines {
+        println!("{}", status);
+    }
+
+    // Refresh marketplace browsing metadata, scoped to marketplaces that
+    // actually had a skill in `wanted` - an update scoped by skill names or
+    // `--exclude` shouldn't touch marketplaces it didn't select. Prefer the
+    // remote skills-index.json (no clone needed) and only fall back to a
+    // full discovery when a marketplace doesn't publish one. One
+    // unreachable marketplace shouldn't abort the whole run, so failures
+    // are reported and skipped rather than propagated with `?`.
+    for marketplace in &config.marketplaces {
+        if !marketplace.enabled.iter().any(|name| wanted.contains(name)) {
+            continue;
+        }
+
+        match refresh_marketplace_metadata(marketplace).await {
+            Ok((git_source, sha, available_skills)) => {
+                lock.marketplaces.insert(marketplace.name.clone(), LockedMarketplace {
+                    source: git_source.url.clone(),
+                    sha,
+                    available_skills,
+                });
+            }
+            Err(e) => {
+                println!("{}: failed to refresh marketplace metadata - {}", marketplace.name, e);
+            }
         }
     }
 
     lock.save(&cwd).await?;
-    
+
     if updated_count > 0 {
         println!("\n{} skill(s) updated in lock file.", updated_count);
         println!("Run 'skills install' to apply updates.");
     } else {
         println!("\nAll skills are up to date.");
     }
-    
+
     Ok(())
 }
+
+/// Resolve `marketplace`'s current SHA and the set of skills it publishes,
+/// for the browsing-metadata refresh at the end of `run`. Split out so a
+/// failure for one marketplace can be caught and reported without `?`
+/// aborting the whole update.
+async fn refresh_marketplace_metadata(marketplace: &Marketplace) -> Result<(GitSource, String, Vec<String>)> {
+    let git_source = GitSource::parse(&marketplace.source)?;
+    let sha = resolve_sha(&git_source).await?;
+
+    let available_skills = match fetch_remote_index(&git_source).await? {
+        Some(index) => index.skills.into_iter().map(|s| s.name).collect(),
+        None => {
+            let cloned = ClonedRepo::at_sha(&git_source, &sha)?;
+            discover_skills(&cloned.path, None).await?
+                .into_iter()
+                .map(|s| s.metadata.name)
+                .collect()
+        }
+    };
+
+    Ok((git_source, sha, available_skills))
+}
+
+/// Resolve (and, if any target actually changed, clone) one distinct
+/// source, producing a lock update for every target skill that came from
+/// it. A target whose locked SHA already matches is reported as `None`.
+async fn resolve_group(
+    group: Vec<UpdateTarget>,
+    current_shas: &HashMap<String, String>,
+) -> Result<Vec<(String, Option<LockedSkill>)>> {
+    let mut git_source = GitSource::parse(&group[0].source)?;
+    if let Some(ref_) = &group[0].ref_ {
+        git_source.ref_ = Some(ref_.clone());
+    }
+    let new_sha = resolve_sha(&git_source).await?;
+
+    let needs_clone = group.iter()
+        .any(|target| current_shas.get(&target.skill_name) != Some(&new_sha));
+
+    let cloned = if needs_clone {
+        Some(ClonedRepo::at_sha(&git_source, &new_sha)?)
+    } else {
+        None
+    };
+
+    let mut results = Vec::with_capacity(group.len());
+    for target in &group {
+        if current_shas.get(&target.skill_name) == Some(&new_sha) {
+            results.push((target.skill_name.clone(), None));
+            continue;
+        }
+
+        let repo = cloned.as_ref().expect("cloned when any target needs updating");
+        let skills = discover_skills(&repo.path, target.path.as_deref()).await?;
+        let skill = skills.into_iter()
+            .find(|s| s.metadata.name == target.skill_name)
+            .ok_or_else(|| anyhow::anyhow!("Skill '{}' not found", target.skill_name))?;
+
+        results.push((target.skill_name.clone(), Some(LockedSkill {
+            name: skill.metadata.name.clone(),
+            source: git_source.url.clone(),
+            sha: new_sha.clone(),
+            ref_: target.ref_.clone(),
+            path: skill.relative_path.clone(),
+            description: Some(skill.metadata.description.clone()),
+            marketplace: target.marketplace.clone(),
+            // The SHA just changed (or this is a fresh lock entry), so any
+            // previous build hook run no longer applies; `install` reruns it.
+            built: false,
+        })));
+    }
+
+    Ok(results)
+}
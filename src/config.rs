@@ -23,6 +23,15 @@ pub struct SkillsConfig {
     /// Individual skills to install
     #[serde(default)]
     pub skills: Vec<SkillSpec>,
+
+    /// Override the target agents skills are installed to: a name ->
+    /// templated destination directory map (e.g.
+    /// `{"myagent": ".config/myagent/skills/{skill}"}`). Falls back to
+    /// `DEFAULT_AGENTS` when absent, so users can support an agent agpm
+    /// doesn't ship knowledge of, or redirect an existing one, without a
+    /// code change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agents: Option<HashMap<String, String>>,
 }
 
 fn default_config_schema() -> String {
@@ -35,10 +44,32 @@ impl Default for SkillsConfig {
             schema: default_config_schema(),
             marketplaces: Vec::new(),
             skills: Vec::new(),
+            agents: None,
         }
     }
 }
 
+/// Default target agents and their templated skill directories, used when
+/// `skills.json` doesn't configure an `[agents]` section of its own.
+pub const DEFAULT_AGENTS: &[(&str, &str)] = &[
+    ("claude-code", ".claude/skills/{skill}"),
+    ("opencode", ".opencode/skills/{skill}"),
+    ("cursor", ".cursor/skills/{skill}"),
+    ("codex", ".codex/skills/{skill}"),
+];
+
+/// Expand a templated agent destination for a specific skill, e.g.
+/// `.config/myagent/skills/{skill}` -> `.config/myagent/skills/pdf-tools`.
+/// A template with no `{skill}` placeholder has the name appended instead,
+/// so a bare directory (as older hand-written configs might use) still works.
+pub fn expand_agent_path(template: &str, skill_name: &str) -> String {
+    if template.contains("{skill}") {
+        template.replace("{skill}", skill_name)
+    } else {
+        format!("{}/{}", template.trim_end_matches('/'), skill_name)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Marketplace {
     /// Name for this marketplace (e.g., "anthropic", "vercel")
@@ -131,7 +162,14 @@ pub struct LockedSkill {
     
     /// Resolved git SHA
     pub sha: String,
-    
+
+    /// Symbolic ref (tag/branch) this SHA was pinned to, if any, recorded
+    /// alongside the resolved `sha`. `update` re-resolves against the ref
+    /// in skills.json, not against this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ref")]
+    pub ref_: Option<String>,
+
     /// Path within repo to skill directory
     pub path: String,
     
@@ -142,11 +180,30 @@ pub struct LockedSkill {
     /// Whether this came from a marketplace
     #[serde(skip_serializing_if = "Option::is_none")]
     pub marketplace: Option<String>,
+
+    /// Whether this skill's `build` hook has already run successfully for
+    /// `sha`. Lets `install` skip re-running it when the SHA hasn't
+    /// changed since the last successful run.
+    #[serde(default)]
+    pub built: bool,
 }
 
 impl SkillsConfig {
     pub const FILENAME: &'static str = "skills.json";
-    
+
+    /// Resolve the configured agent targets, falling back to
+    /// `DEFAULT_AGENTS` when `[agents]` isn't present (or is empty) in
+    /// skills.json. Centralized here so `install` and `remove` always
+    /// agree on where a skill lives.
+    pub fn agent_targets(&self) -> Vec<(String, String)> {
+        match &self.agents {
+            Some(agents) if !agents.is_empty() => {
+                agents.iter().map(|(name, dir)| (name.clone(), dir.clone())).collect()
+            }
+            _ => DEFAULT_AGENTS.iter().map(|(name, dir)| (name.to_string(), dir.to_string())).collect(),
+        }
+    }
+
     pub async fn load(dir: &Path) -> Result<Self> {
         let path = dir.join(Self::FILENAME);
         if !path.exists() {
@@ -167,9 +224,14 @@ impl SkillsConfig {
     }
 }
 
+/// Current on-disk lock file format version. Bump this and add a
+/// `migrate_vN_to_vN1` step whenever the shape of `SkillsLock` changes in
+/// a way that isn't just adding a `#[serde(default)]` field.
+pub const CURRENT_LOCK_VERSION: u32 = 1;
+
 impl SkillsLock {
     pub const FILENAME: &'static str = "skills-lock.json";
-    
+
     pub async fn load(dir: &Path) -> Result<Self> {
         let path = dir.join(Self::FILENAME);
         if !path.exists() {
@@ -177,10 +239,30 @@ impl SkillsLock {
         }
         let content = fs::read_to_string(&path).await
             .context("Failed to read skills-lock.json")?;
-        serde_json::from_str(&content)
-            .context("Failed to parse skills-lock.json")
+
+        let raw: serde_json::Value = serde_json::from_str(&content)
+            .context("Failed to parse skills-lock.json")?;
+        let on_disk_version = raw.get("version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+
+        if on_disk_version > CURRENT_LOCK_VERSION {
+            anyhow::bail!(
+                "skills-lock.json (version {}) was written by a newer agpm; please upgrade",
+                on_disk_version
+            );
+        }
+
+        let migrated = migrate(raw, on_disk_version);
+        let lock: SkillsLock = serde_json::from_value(migrated)
+            .context("Failed to parse skills-lock.json")?;
+
+        if on_disk_version < CURRENT_LOCK_VERSION {
+            lock.save(dir).await
+                .context("Failed to save migrated skills-lock.json")?;
+        }
+
+        Ok(lock)
     }
-    
+
     pub async fn save(&self, dir: &Path) -> Result<()> {
         let path = dir.join(Self::FILENAME);
         let content = serde_json::to_string_pretty(self)?;
@@ -189,3 +271,41 @@ impl SkillsLock {
         Ok(())
     }
 }
+
+/// Walk a raw lock file JSON value through each version's upgrade step up
+/// to `CURRENT_LOCK_VERSION`, so `serde_json::from_value` always sees the
+/// current in-memory shape regardless of which version wrote the file.
+fn migrate(mut value: serde_json::Value, mut version: u32) -> serde_json::Value {
+    if version == 0 {
+        value = migrate_v0_to_v1(value);
+        version = 1;
+    }
+
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert("version".to_string(), serde_json::json!(version));
+    }
+
+    value
+}
+
+/// v0 lock files predate the `marketplaces` map and predate
+/// `available_skills` on each locked marketplace; fill in the defaults
+/// the current struct expects rather than relying on serde to invent
+/// them (serde's `#[serde(default)]` only helps for fields missing from
+/// an otherwise-well-formed object, not for a whole-object reshape).
+fn migrate_v0_to_v1(mut value: serde_json::Value) -> serde_json::Value {
+    if let Some(obj) = value.as_object_mut() {
+        obj.entry("marketplaces").or_insert_with(|| serde_json::json!({}));
+        obj.entry("skills").or_insert_with(|| serde_json::json!({}));
+
+        if let Some(marketplaces) = obj.get_mut("marketplaces").and_then(|m| m.as_object_mut()) {
+            for marketplace in marketplaces.values_mut() {
+                if let Some(m) = marketplace.as_object_mut() {
+                    m.entry("available_skills").or_insert_with(|| serde_json::json!([]));
+                }
+            }
+        }
+    }
+
+    value
+}
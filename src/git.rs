@@ -1,11 +1,11 @@
 //! Git operations for cloning repos and resolving SHAs
 
 use anyhow::{Result, Context};
-use git2::{FetchOptions, RemoteCallbacks, Cred};
 use std::path::PathBuf;
-use tempfile::TempDir;
 use regex::Regex;
 
+use crate::cache::RepoCache;
+
 /// Parsed git source information
 #[derive(Debug, Clone)]
 pub struct GitSource {
@@ -86,6 +86,65 @@ impl GitSource {
             });
         }
 
+        // Bitbucket tree URL with path: bitbucket.org/owner/repo/src/ref/path
+        let bitbucket_tree_path = Regex::new(
+            r"bitbucket\.org/([^/]+)/([^/]+)/src/([^/]+)/(.+)"
+        ).unwrap();
+        if let Some(caps) = bitbucket_tree_path.captures(input) {
+            return Ok(Self {
+                url: format!("https://bitbucket.org/{}/{}.git", &caps[1], &caps[2]),
+                owner: Some(caps[1].to_string()),
+                repo: Some(caps[2].to_string()),
+                ref_: Some(caps[3].to_string()),
+                subpath: Some(caps[4].to_string()),
+            });
+        }
+
+        // Bitbucket tree URL without path: bitbucket.org/owner/repo/src/ref
+        let bitbucket_tree = Regex::new(
+            r"bitbucket\.org/([^/]+)/([^/]+)/src/([^/]+)$"
+        ).unwrap();
+        if let Some(caps) = bitbucket_tree.captures(input) {
+            return Ok(Self {
+                url: format!("https://bitbucket.org/{}/{}.git", &caps[1], &caps[2]),
+                owner: Some(caps[1].to_string()),
+                repo: Some(caps[2].to_string()),
+                ref_: Some(caps[3].to_string()),
+                subpath: None,
+            });
+        }
+
+        // Gitea/Forgejo tree URL with path: <host>/owner/repo/src/branch/ref/path
+        // (these are self-hosted, so the host itself isn't fixed)
+        let gitea_tree_path = Regex::new(
+            r"^https?://([^/]+)/([^/]+)/([^/]+)/src/branch/([^/]+)/(.+)$"
+        ).unwrap();
+        if let Some(caps) = gitea_tree_path.captures(input) {
+            let host = &caps[1];
+            return Ok(Self {
+                url: format!("https://{}/{}/{}.git", host, &caps[2], &caps[3]),
+                owner: Some(caps[2].to_string()),
+                repo: Some(caps[3].to_string()),
+                ref_: Some(caps[4].to_string()),
+                subpath: Some(caps[5].to_string()),
+            });
+        }
+
+        // Gitea/Forgejo tree URL without path: <host>/owner/repo/src/branch/ref
+        let gitea_tree = Regex::new(
+            r"^https?://([^/]+)/([^/]+)/([^/]+)/src/branch/([^/]+)$"
+        ).unwrap();
+        if let Some(caps) = gitea_tree.captures(input) {
+            let host = &caps[1];
+            return Ok(Self {
+                url: format!("https://{}/{}/{}.git", host, &caps[2], &caps[3]),
+                owner: Some(caps[2].to_string()),
+                repo: Some(caps[3].to_string()),
+                ref_: Some(caps[4].to_string()),
+                subpath: None,
+            });
+        }
+
         // SSH URL: git@github.com:owner/repo.git
         let ssh_url = Regex::new(
             r"git@([^:]+):([^/]+)/([^/]+?)(?:\.git)?$"
@@ -101,14 +160,33 @@ impl GitSource {
             });
         }
 
-        // Shorthand: owner/repo or owner/repo/subpath
+        // Shorthand with a pinned ref: owner/repo@ref or
+        // owner/repo/subpath@ref, cargo-`add`-style (`owner/repo@v1.2.0`,
+        // `owner/repo@main`). Checked before the plain shorthand case below
+        // since that one has no way to carry a ref.
+        let shorthand_with_ref = Regex::new(
+            r"^([^/@]+)/([^/@]+)(?:/([^@]+))?@([^/@]+)$"
+        ).unwrap();
+        if let Some(caps) = shorthand_with_ref.captures(input) {
+            return Ok(Self {
+                url: format!("https://{}/{}/{}.git", default_host(), &caps[1], &caps[2]),
+                owner: Some(caps[1].to_string()),
+                repo: Some(caps[2].to_string()),
+                ref_: Some(caps[4].to_string()),
+                subpath: caps.get(3).map(|m| m.as_str().to_string()),
+            });
+        }
+
+        // Shorthand: owner/repo or owner/repo/subpath, resolved against the
+        // default host (github.com, or $AGPM_DEFAULT_HOST when set, so
+        // private/self-hosted forges can be the implicit default too).
         let shorthand = Regex::new(
             r"^([^/]+)/([^/]+)(?:/(.+))?$"
         ).unwrap();
         if let Some(caps) = shorthand.captures(input) {
             if !input.contains(':') && !input.starts_with('.') && !input.starts_with('/') {
                 return Ok(Self {
-                    url: format!("https://github.com/{}/{}.git", &caps[1], &caps[2]),
+                    url: format!("https://{}/{}/{}.git", default_host(), &caps[1], &caps[2]),
                     owner: Some(caps[1].to_string()),
                     repo: Some(caps[2].to_string()),
                     ref_: None,
@@ -137,51 +215,112 @@ impl GitSource {
     }
 }
 
-/// A cloned repository with its temp directory
+/// Host that bare `owner/repo` shorthand resolves against. Defaults to
+/// github.com; set `AGPM_DEFAULT_HOST` to point it at a private or
+/// self-hosted forge instead.
+fn default_host() -> String {
+    std::env::var("AGPM_DEFAULT_HOST").unwrap_or_else(|_| "github.com".to_string())
+}
+
+/// Extract the host component from a `https://host/...` or `git@host:...` URL.
+pub(crate) fn host_of(url: &str) -> Option<String> {
+    if let Some(rest) = url.strip_prefix("git@") {
+        return rest.split(':').next().map(|h| h.to_string());
+    }
+    url.split("://").nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .map(|h| h.to_string())
+}
+
+/// Which forge a host belongs to, so `resolve_sha` can hit the right
+/// commits API and parse its response shape.
+pub(crate) enum Forge {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    Gitea,
+}
+
+/// Detect the forge for a host. Gitea/Forgejo instances are self-hosted
+/// under arbitrary domains, so they're recognized via an explicit
+/// `AGPM_GITEA_HOSTS` comma-separated allowlist rather than by name.
+pub(crate) fn forge_for_host(host: &str) -> Option<Forge> {
+    match host {
+        "github.com" => Some(Forge::GitHub),
+        "gitlab.com" => Some(Forge::GitLab),
+        "bitbucket.org" => Some(Forge::Bitbucket),
+        _ => {
+            let gitea_hosts = std::env::var("AGPM_GITEA_HOSTS").unwrap_or_default();
+            gitea_hosts.split(',').any(|h| h.trim() == host).then_some(Forge::Gitea)
+        }
+    }
+}
+
+/// Look up a configured token for a host: a well-known env var for the
+/// big public forges, or `AGPM_TOKEN_<HOST>` (host upper-cased, `.`/`-`
+/// replaced with `_`) for anything else.
+pub(crate) fn token_for_host(host: &str) -> Option<String> {
+    match host {
+        "github.com" => std::env::var("GITHUB_TOKEN").ok(),
+        "gitlab.com" => std::env::var("GITLAB_TOKEN").ok(),
+        other => {
+            let key = format!("AGPM_TOKEN_{}", other.to_uppercase().replace(['.', '-'], "_"));
+            std::env::var(key).ok()
+        }
+    }
+}
+
+/// A checked-out repository, materialized from the content-addressed cache
 pub struct ClonedRepo {
     pub path: PathBuf,
     pub sha: String,
-    _temp_dir: TempDir,
 }
 
 impl ClonedRepo {
-    /// Clone a repository and return the cloned repo info
+    /// Clone (or reuse) a repository at its ref/default branch and return
+    /// the checkout info. Backed by `RepoCache`: the bare mirror is fetched
+    /// at most once per call, and if the resolved SHA was already
+    /// materialized by a previous call, that checkout is returned as-is.
     pub fn clone(source: &GitSource) -> Result<Self> {
-        let temp_dir = TempDir::new()
-            .context("Failed to create temp directory")?;
-        
-        let mut callbacks = RemoteCallbacks::new();
-        callbacks.credentials(|_url, username_from_url, _allowed_types| {
-            // Try SSH agent first
-            Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
-        });
-
-        let mut fetch_opts = FetchOptions::new();
-        fetch_opts.remote_callbacks(callbacks);
-        fetch_opts.depth(1);
-
-        let mut builder = git2::build::RepoBuilder::new();
-        builder.fetch_options(fetch_opts);
-
-        if let Some(ref_) = &source.ref_ {
-            builder.branch(ref_);
+        let cache = RepoCache::open()?;
+        let mirror = cache.ensure_mirror(source)?;
+        let sha = cache.resolve_local_sha(source, &mirror)?;
+        let path = cache.checkout(source, &mirror, &sha)?;
+
+        Ok(Self { path, sha })
+    }
+
+    /// Clone (or reuse) a repository at a specific, already-resolved SHA.
+    /// Used when the caller knows the exact commit it wants (e.g. from a
+    /// lock file or a prior `resolve_sha` call) so it never needs to guess
+    /// at a ref. If that SHA was already materialized by a previous call,
+    /// this never touches the network, which is what makes offline
+    /// installs of already-cached commits possible.
+    pub fn at_sha(source: &GitSource, sha: &str) -> Result<Self> {
+        let cache = RepoCache::open()?;
+
+        if let Some(path) = cache.cached_checkout(source, sha) {
+            return Ok(Self { path, sha: sha.to_string() });
         }
 
-        let repo = builder.clone(&source.url, temp_dir.path())
-            .context(format!("Failed to clone {}", source.url))?;
+        let mirror = cache.ensure_mirror(source)?;
+        let path = cache.checkout(source, &mirror, sha)?;
 
-        let head = repo.head()
-            .context("Failed to get HEAD")?;
-        let sha = head.peel_to_commit()
-            .context("Failed to get commit")?
-            .id()
-            .to_string();
+        Ok(Self { path, sha: sha.to_string() })
+    }
 
-        Ok(Self {
-            path: temp_dir.path().to_path_buf(),
-            sha,
-            _temp_dir: temp_dir,
-        })
+    /// Like `at_sha`, but never touches the network: if `sha` hasn't
+    /// already been materialized by a previous call, this fails instead of
+    /// fetching. Backs `install --frozen` for fully offline, auditable
+    /// installs.
+    pub fn at_sha_frozen(source: &GitSource, sha: &str) -> Result<Self> {
+        let cache = RepoCache::open()?;
+        cache.cached_checkout(source, sha)
+            .map(|path| Self { path, sha: sha.to_string() })
+            .ok_or_else(|| anyhow::anyhow!(
+                "'{}' @ {} is not in the local cache and --frozen forbids network access",
+                source.url, sha
+            ))
     }
 
     /// Get path to a subpath within the repo
@@ -194,37 +333,82 @@ impl ClonedRepo {
     }
 }
 
-/// Resolve the latest SHA for a git source without cloning
+/// Resolve the latest SHA for a git source without cloning, using each
+/// forge's commits API when we recognize the host (faster than cloning),
+/// with a fallback through the cached mirror for anything else.
 pub async fn resolve_sha(source: &GitSource) -> Result<String> {
-    // Use GitHub API for GitHub repos (faster than cloning)
     if let (Some(owner), Some(repo)) = (&source.owner, &source.repo) {
-        if source.url.contains("github.com") {
+        let host = host_of(&source.url);
+        let forge = host.as_deref().and_then(forge_for_host);
+
+        if let Some(forge) = forge {
             let ref_ = source.ref_.as_deref().unwrap_or("HEAD");
-            let url = format!(
-                "https://api.github.com/repos/{}/{}/commits/{}",
-                owner, repo, ref_
-            );
-            
             let client = reqwest::Client::new();
-            let resp = client.get(&url)
-                .header("User-Agent", "skills-cli")
-                .header("Accept", "application/vnd.github.v3+json")
-                .send()
-                .await
-                .context("Failed to fetch commit info from GitHub")?;
+            let mut request = client.get(commits_api_url(&forge, &host.unwrap(), owner, repo, ref_))
+                .header("User-Agent", "skills-cli");
+
+            if let Some(token) = source_token(source) {
+                request = match forge {
+                    Forge::GitHub => request.header("Authorization", format!("Bearer {}", token)),
+                    // GitLab's REST API doesn't honor `Authorization: token
+                    // ...`; it wants a dedicated `PRIVATE-TOKEN` header.
+                    Forge::GitLab => request.header("PRIVATE-TOKEN", token),
+                    Forge::Gitea => request.header("Authorization", format!("token {}", token)),
+                    Forge::Bitbucket => request.header("Authorization", format!("Bearer {}", token)),
+                };
+            }
+
+            let resp = request.send().await
+                .context(format!("Failed to fetch commit info from {}", source.url))?;
 
             if resp.status().is_success() {
                 let data: serde_json::Value = resp.json().await?;
-                if let Some(sha) = data["sha"].as_str() {
+                if let Some(sha) = sha_from_response(&forge, &data) {
                     return Ok(sha.to_string());
                 }
             }
         }
     }
 
-    // Fall back to cloning
-    let cloned = ClonedRepo::clone(source)?;
-    Ok(cloned.sha)
+    // Unrecognized host (or the API path failed): fetch refs into the
+    // cached mirror and read the target ref locally rather than doing a
+    // one-shot clone just to inspect HEAD.
+    let cache = RepoCache::open()?;
+    let mirror = cache.ensure_mirror(source)?;
+    cache.resolve_local_sha(source, &mirror)
+}
+
+fn commits_api_url(forge: &Forge, host: &str, owner: &str, repo: &str, ref_: &str) -> String {
+    match forge {
+        Forge::GitHub => format!("https://api.github.com/repos/{}/{}/commits/{}", owner, repo, ref_),
+        Forge::GitLab => format!(
+            "https://{}/api/v4/projects/{}%2F{}/repository/commits/{}",
+            host, owner, repo, ref_
+        ),
+        Forge::Gitea => format!("https://{}/api/v1/repos/{}/{}/commits/{}", host, owner, repo, ref_),
+        Forge::Bitbucket => format!("https://api.bitbucket.org/2.0/repositories/{}/{}/commit/{}", owner, repo, ref_),
+    }
+}
+
+/// Pull the commit SHA out of each forge's differently-shaped response.
+/// Gitea's `/commits/{ref}` returns an array (most recent first) rather
+/// than a single object.
+fn sha_from_response(forge: &Forge, data: &serde_json::Value) -> Option<String> {
+    match forge {
+        Forge::GitHub | Forge::Bitbucket => data["sha"].as_str()
+            .or_else(|| data["hash"].as_str())
+            .map(|s| s.to_string()),
+        Forge::GitLab => data["id"].as_str().map(|s| s.to_string()),
+        Forge::Gitea => data.as_array()
+            .and_then(|commits| commits.first())
+            .and_then(|commit| commit["sha"].as_str())
+            .map(|s| s.to_string()),
+    }
+}
+
+/// Resolve the auth token for a source's host, if one is configured.
+pub(crate) fn source_token(source: &GitSource) -> Option<String> {
+    host_of(&source.url).as_deref().and_then(token_for_host)
 }
 
 #[cfg(test)]
@@ -264,4 +448,51 @@ mod tests {
         assert_eq!(source.ref_, Some("main".to_string()));
         assert_eq!(source.subpath, Some("skills/pdf".to_string()));
     }
+
+    #[test]
+    fn test_parse_bitbucket_tree_url() {
+        let source = GitSource::parse(
+            "https://bitbucket.org/acme/agent-skills/src/main/skills/pdf"
+        ).unwrap();
+        assert_eq!(source.owner, Some("acme".to_string()));
+        assert_eq!(source.repo, Some("agent-skills".to_string()));
+        assert_eq!(source.ref_, Some("main".to_string()));
+        assert_eq!(source.subpath, Some("skills/pdf".to_string()));
+    }
+
+    #[test]
+    fn test_parse_gitea_tree_url() {
+        let source = GitSource::parse(
+            "https://git.example.com/acme/agent-skills/src/branch/main/skills/pdf"
+        ).unwrap();
+        assert_eq!(source.url, "https://git.example.com/acme/agent-skills.git");
+        assert_eq!(source.owner, Some("acme".to_string()));
+        assert_eq!(source.repo, Some("agent-skills".to_string()));
+        assert_eq!(source.ref_, Some("main".to_string()));
+        assert_eq!(source.subpath, Some("skills/pdf".to_string()));
+    }
+
+    #[test]
+    fn test_parse_shorthand_with_pinned_ref() {
+        let source = GitSource::parse("anthropics/skills@v1.2.0").unwrap();
+        assert_eq!(source.owner, Some("anthropics".to_string()));
+        assert_eq!(source.repo, Some("skills".to_string()));
+        assert_eq!(source.ref_, Some("v1.2.0".to_string()));
+        assert_eq!(source.subpath, None);
+    }
+
+    #[test]
+    fn test_parse_shorthand_with_subpath_and_pinned_ref() {
+        let source = GitSource::parse("vercel-labs/agent-skills/skills/pdf@main").unwrap();
+        assert_eq!(source.owner, Some("vercel-labs".to_string()));
+        assert_eq!(source.repo, Some("agent-skills".to_string()));
+        assert_eq!(source.subpath, Some("skills/pdf".to_string()));
+        assert_eq!(source.ref_, Some("main".to_string()));
+    }
+
+    #[test]
+    fn test_host_of() {
+        assert_eq!(host_of("https://github.com/owner/repo.git").as_deref(), Some("github.com"));
+        assert_eq!(host_of("git@github.com:owner/repo.git").as_deref(), Some("github.com"));
+    }
 }
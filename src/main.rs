@@ -1,7 +1,10 @@
+mod build;
+mod cache;
 mod cli;
 mod config;
 mod git;
 mod skill;
+mod suggest;
 mod tui;
 
 use anyhow::Result;
@@ -16,11 +19,11 @@ async fn main() -> Result<()> {
         Some(Commands::Add { source, skill }) => {
             cli::add::run(&source, skill.as_deref()).await?;
         }
-        Some(Commands::Install) => {
-            cli::install::run().await?;
+        Some(Commands::Install { locked, frozen }) => {
+            cli::install::run(locked, frozen).await?;
         }
-        Some(Commands::Update { skill }) => {
-            cli::update::run(skill.as_deref()).await?;
+        Some(Commands::Update { skills, exclude, jobs }) => {
+            cli::update::run(&skills, &exclude, jobs).await?;
         }
         Some(Commands::List) => {
             cli::list::run().await?;
@@ -28,6 +31,15 @@ async fn main() -> Result<()> {
         Some(Commands::Remove { skill }) => {
             cli::remove::run(&skill).await?;
         }
+        Some(Commands::Cache { action }) => {
+            cli::cache::run(action).await?;
+        }
+        Some(Commands::Index { path }) => {
+            cli::index::run(path.as_deref()).await?;
+        }
+        Some(Commands::Edit) => {
+            cli::edit::run().await?;
+        }
         None => {
             // Interactive TUI mode
             tui::run().await?;
@@ -5,11 +5,78 @@ use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use tokio::fs;
 
+use crate::git::GitSource;
+
+/// Filename of the optional marketplace browsing index, committed at a
+/// marketplace repo's root.
+pub const SKILLS_INDEX_FILENAME: &str = "skills-index.json";
+
+/// One entry in a marketplace's `skills-index.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillIndexEntry {
+    pub name: String,
+    pub description: String,
+    pub path: String,
+    pub sha: String,
+}
+
+/// A marketplace's browsing index: enough to list and filter skills
+/// without cloning the repo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillsIndex {
+    pub skills: Vec<SkillIndexEntry>,
+}
+
+/// Fetch `skills-index.json` from a marketplace repo's root over HTTPS
+/// (GitHub raw content), without cloning. Returns `None` when the source
+/// isn't a host we know how to fetch raw files from, or when the repo
+/// simply doesn't have an index yet.
+pub async fn fetch_remote_index(source: &GitSource) -> Result<Option<SkillsIndex>> {
+    let Some(url) = raw_index_url(source) else {
+        return Ok(None);
+    };
+
+    let client = reqwest::Client::new();
+    let resp = client.get(&url)
+        .header("User-Agent", "skills-cli")
+        .send()
+        .await
+        .context("Failed to fetch skills-index.json")?;
+
+    if !resp.status().is_success() {
+        return Ok(None);
+    }
+
+    let index: SkillsIndex = resp.json().await
+        .context("Failed to parse skills-index.json")?;
+    Ok(Some(index))
+}
+
+fn raw_index_url(source: &GitSource) -> Option<String> {
+    if !source.url.contains("github.com") {
+        return None;
+    }
+    let owner = source.owner.as_ref()?;
+    let repo = source.repo.as_ref()?;
+    let ref_ = source.ref_.as_deref().unwrap_or("HEAD");
+
+    Some(format!(
+        "https://raw.githubusercontent.com/{}/{}/{}/{}",
+        owner, repo, ref_, SKILLS_INDEX_FILENAME
+    ))
+}
+
 /// Parsed skill metadata from SKILL.md frontmatter
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillMetadata {
     pub name: String,
     pub description: String,
+    /// Shell commands to run once, in order, after this skill is checked out
+    /// and before it is copied into the agent directories (e.g. compiling a
+    /// helper, generating assets). Supports `{{ name }}`
+    /// and `{{ path }}` template variables; see `crate::build`.
+    #[serde(default, alias = "prepare")]
+    pub build: Vec<String>,
     #[serde(flatten)]
     pub extra: serde_yaml::Value,
 }
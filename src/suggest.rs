@@ -0,0 +1,85 @@
+//! "Did you mean...?" suggestions for mistyped skill/marketplace names
+
+/// Compute the Levenshtein edit distance between two strings.
+///
+/// Classic two-row DP: keep a `prev` row of length `b.len() + 1` seeded
+/// with `0..=n`, then for each character of `a` build a `cur` row where
+/// `cur[j + 1]` is the minimum of a deletion, insertion, or
+/// substitution, swapping rows as we go. The answer is `prev[b.len()]`
+/// after processing all of `a`.
+pub fn lev_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let n = b.len();
+
+    let mut prev: Vec<usize> = (0..=n).collect();
+    let mut cur: Vec<usize> = vec![0; n + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca != cb { 1 } else { 0 };
+            cur[j + 1] = (prev[j + 1] + 1).min(cur[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[n]
+}
+
+/// Find the closest candidate to `name`, mirroring cargo's suggestion
+/// threshold: a candidate is only suggested when its distance is within
+/// `max(candidate.len(), 1) / 3`.
+pub fn suggest<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = lev_distance(name, candidate);
+            let threshold = (candidate.len().max(1)) / 3;
+            (distance <= threshold).then_some((distance, candidate))
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Format a "not found" error message, appending a "did you mean" hint
+/// when a close candidate exists.
+pub fn not_found_message<'a, I>(kind: &str, name: &str, candidates: I) -> String
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    match suggest(name, candidates) {
+        Some(candidate) => format!("{} '{}' not found; did you mean '{}'?", kind, name, candidate),
+        None => format!("{} '{}' not found", kind, name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lev_distance_identical() {
+        assert_eq!(lev_distance("pdf", "pdf"), 0);
+    }
+
+    #[test]
+    fn test_lev_distance_typo() {
+        assert_eq!(lev_distance("pdf-tools", "pdf-tool"), 1);
+    }
+
+    #[test]
+    fn test_suggest_picks_closest() {
+        let candidates = vec!["pdf-tools", "docx-tools", "image-tools"];
+        assert_eq!(suggest("pdf-tool", candidates), Some("pdf-tools"));
+    }
+
+    #[test]
+    fn test_suggest_none_when_too_far() {
+        let candidates = vec!["pdf-tools", "docx-tools"];
+        assert_eq!(suggest("completely-different", candidates), None);
+    }
+}
@@ -1,6 +1,7 @@
 //! TUI application state
 
 use anyhow::Result;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::config::{SkillsConfig, SkillsLock, Marketplace, SkillSpec};
@@ -33,7 +34,17 @@ pub struct App {
     // Current marketplace being browsed
     pub current_marketplace: Option<String>,
     pub marketplace_available_skills: Vec<String>,
-    
+    pub marketplace_skill_descriptions: HashMap<String, String>,
+
+    // Cloned marketplace repo paths, keyed by marketplace name, so
+    // navigating back into a marketplace already browsed this session
+    // doesn't re-clone it.
+    marketplace_clone_cache: HashMap<String, PathBuf>,
+
+    // Incremental fuzzy search over marketplace_available_skills
+    pub marketplace_skill_query: String,
+    pub marketplace_skill_filtering: bool,
+
     // Status message
     pub status: String,
 }
@@ -55,6 +66,10 @@ impl App {
             selected_marketplace_skill: 0,
             current_marketplace: None,
             marketplace_available_skills: Vec::new(),
+            marketplace_skill_descriptions: HashMap::new(),
+            marketplace_clone_cache: HashMap::new(),
+            marketplace_skill_query: String::new(),
+            marketplace_skill_filtering: false,
             status: String::new(),
         })
     }
@@ -152,27 +167,40 @@ impl App {
             return Ok(());
         }
         
-        // Add first skill (TODO: selection UI if multiple)
-        let skill = &skills[0];
-        let spec = SkillSpec {
-            name: skill.metadata.name.clone(),
-            source: git_source.canonical(),
-            ref_: git_source.ref_.clone(),
-            path: if skill.relative_path.is_empty() {
-                None
-            } else {
-                Some(skill.relative_path.clone())
-            },
-        };
-        
-        // Check if exists
-        if !self.config.skills.iter().any(|s| s.name == spec.name) {
-            self.config.skills.push(spec.clone());
-        }
-        
+        // `cli::add` offers an interactive fuzzy multi-select
+        // (`tui::picker::multi_select`) when a source has more than one
+        // skill, but that picker runs its own raw-mode/alt-screen session
+        // and can't be nested inside the main `App` loop's own terminal.
+        // Add every discovered skill instead of silently guessing one;
+        // unwanted ones can be dropped with 'd' from the skill list.
+        let added: Vec<&str> = skills.iter()
+            .filter(|skill| {
+                let spec = SkillSpec {
+                    name: skill.metadata.name.clone(),
+                    source: git_source.canonical(),
+                    ref_: git_source.ref_.clone(),
+                    path: if skill.relative_path.is_empty() {
+                        None
+                    } else {
+                        Some(skill.relative_path.clone())
+                    },
+                };
+                let is_new = !self.config.skills.iter().any(|s| s.name == spec.name);
+                if is_new {
+                    self.config.skills.push(spec);
+                }
+                is_new
+            })
+            .map(|skill| skill.metadata.name.as_str())
+            .collect();
+
         self.config.save(&self.cwd).await?;
-        self.status = format!("Added '{}' - run install to download", spec.name);
-        
+        self.status = if added.is_empty() {
+            "Already in skills.json".to_string()
+        } else {
+            format!("Added {} - run install to download", added.join(", "))
+        };
+
         Ok(())
     }
     
@@ -180,7 +208,7 @@ impl App {
         self.status = "Installing skills...".to_string();
         
         // Run install logic
-        crate::cli::install::run().await?;
+        crate::cli::install::run(false, false).await?;
         
         // Reload lock file
         self.lock = SkillsLock::load(&self.cwd).await?;
@@ -189,14 +217,22 @@ impl App {
         Ok(())
     }
     
+    /// Update every skill. `update_skills_targeted` takes the same
+    /// `skills`/`exclude` target-set shape as the CLI so a future
+    /// multi-select can update just the checked rows by passing their
+    /// names as `skills`.
     pub async fn update_skills(&mut self) -> Result<()> {
+        self.update_skills_targeted(&[], &[]).await
+    }
+
+    pub async fn update_skills_targeted(&mut self, skills: &[String], exclude: &[String]) -> Result<()> {
         self.status = "Updating skills...".to_string();
-        
-        crate::cli::update::run(None).await?;
-        
+
+        crate::cli::update::run(skills, exclude, None).await?;
+
         self.lock = SkillsLock::load(&self.cwd).await?;
         self.status = "Update complete".to_string();
-        
+
         Ok(())
     }
     
@@ -215,14 +251,77 @@ impl App {
         }
     }
     
-    pub fn enter_marketplace(&mut self) {
-        if let Some(marketplace) = self.config.marketplaces.get(self.selected_marketplace) {
-            self.current_marketplace = Some(marketplace.name.clone());
-            self.state = AppState::MarketplaceSkills;
-            self.selected_marketplace_skill = 0;
-            // TODO: Load available skills from marketplace
-            self.marketplace_available_skills = marketplace.enabled.clone();
-        }
+    /// Enter the Marketplace screen and populate it with a live catalog:
+    /// clone the marketplace repo (reusing the session cache if it was
+    /// already cloned) and discover every skill it contains, so browsing
+    /// isn't limited to skills already enabled or indexed by a prior
+    /// `skills update`.
+    pub async fn enter_marketplace(&mut self) -> Result<()> {
+        let Some(marketplace) = self.config.marketplaces.get(self.selected_marketplace).cloned() else {
+            return Ok(());
+        };
+
+        self.current_marketplace = Some(marketplace.name.clone());
+        self.state = AppState::MarketplaceSkills;
+        self.selected_marketplace_skill = 0;
+        self.marketplace_skill_query.clear();
+        self.marketplace_skill_filtering = false;
+        self.status = format!("Loading skills from '{}'...", marketplace.name);
+
+        let repo_path = match self.marketplace_clone_cache.get(&marketplace.name) {
+            Some(path) => path.clone(),
+            None => {
+                let mut git_source = GitSource::parse(&marketplace.source)?;
+                if let Some(ref_) = &marketplace.ref_ {
+                    git_source.ref_ = Some(ref_.clone());
+                }
+                let cloned = ClonedRepo::clone(&git_source)?;
+                self.marketplace_clone_cache.insert(marketplace.name.clone(), cloned.path.clone());
+                cloned.path
+            }
+        };
+
+        let skills = discover_skills(&repo_path, None).await?;
+        self.marketplace_skill_descriptions = skills.iter()
+            .map(|s| (s.metadata.name.clone(), s.metadata.description.clone()))
+            .collect();
+        self.marketplace_available_skills = skills.into_iter().map(|s| s.metadata.name).collect();
+        self.marketplace_available_skills.sort();
+
+        self.status = format!(
+            "{}: {} skill(s) discovered",
+            marketplace.name, self.marketplace_available_skills.len()
+        );
+
+        Ok(())
+    }
+
+    /// `marketplace_available_skills` ranked against the current filter
+    /// query, as `(index into marketplace_available_skills, matched char
+    /// positions)`. An empty query matches everything in its original order.
+    pub fn filtered_marketplace_skills(&self) -> Vec<(usize, Vec<usize>)> {
+        crate::tui::fuzzy::fuzzy_filter(
+            self.marketplace_available_skills.iter().enumerate().map(|(i, s)| (i, s.as_str())),
+            &self.marketplace_skill_query,
+        )
+    }
+
+    pub fn enter_skill_filter(&mut self) {
+        self.marketplace_skill_filtering = true;
+    }
+
+    pub fn exit_skill_filter(&mut self) {
+        self.marketplace_skill_filtering = false;
+    }
+
+    pub fn push_skill_filter_char(&mut self, c: char) {
+        self.marketplace_skill_query.push(c);
+        self.selected_marketplace_skill = 0;
+    }
+
+    pub fn pop_skill_filter_char(&mut self) {
+        self.marketplace_skill_query.pop();
+        self.selected_marketplace_skill = 0;
     }
     
     pub async fn add_marketplace_from_input(&mut self) -> Result<()> {
@@ -283,26 +382,31 @@ impl App {
         Ok(())
     }
     
-    // Marketplace skills navigation
+    // Marketplace skills navigation (operates on the filtered list so a
+    // search query never strands the cursor on a hidden row)
     pub fn next_marketplace_skill(&mut self) {
-        let len = self.marketplace_available_skills.len();
+        let len = self.filtered_marketplace_skills().len();
         if len > 0 {
             self.selected_marketplace_skill = (self.selected_marketplace_skill + 1) % len;
         }
     }
-    
+
     pub fn previous_marketplace_skill(&mut self) {
-        let len = self.marketplace_available_skills.len();
+        let len = self.filtered_marketplace_skills().len();
         if len > 0 {
             self.selected_marketplace_skill = self.selected_marketplace_skill.checked_sub(1).unwrap_or(len - 1);
         }
     }
-    
+
     pub async fn toggle_marketplace_skill(&mut self) -> Result<()> {
         if let Some(marketplace_name) = &self.current_marketplace.clone() {
-            if let Some(skill_name) = self.marketplace_available_skills.get(self.selected_marketplace_skill) {
+            let filtered = self.filtered_marketplace_skills();
+            let skill_name = filtered.get(self.selected_marketplace_skill)
+                .and_then(|(idx, _)| self.marketplace_available_skills.get(*idx));
+
+            if let Some(skill_name) = skill_name {
                 let skill_name = skill_name.clone();
-                
+
                 if let Some(marketplace) = self.config.marketplaces.iter_mut()
                     .find(|m| &m.name == marketplace_name) 
                 {
@@ -0,0 +1,92 @@
+//! Subsequence fuzzy matching for incremental search
+//!
+//! A minimal fzf/navi-style matcher: a candidate matches if `query`'s
+//! characters appear in `text` in order (not necessarily contiguous), and
+//! candidates that match with tighter runs and an earlier first match score
+//! higher. Good enough for filtering a few dozen skill names without
+//! pulling in an external fuzzy-matching crate.
+
+/// Try to match `query` as a subsequence of `text` (case-insensitive).
+/// Returns a score (higher is better) plus the char-index positions in
+/// `text` that matched, for highlighting. `None` if it isn't a subsequence.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let text_lower: Vec<char> = text.to_lowercase().chars().collect();
+
+    let mut positions = Vec::with_capacity(query.len());
+    let mut qi = 0;
+    let mut score: i64 = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for (ti, ch) in text_lower.iter().enumerate() {
+        if qi >= query.len() {
+            break;
+        }
+        if *ch == query[qi] {
+            let contiguous = prev_match == Some(ti.wrapping_sub(1));
+            score += if contiguous { 3 } else { 1 };
+            if qi == 0 {
+                // Reward an early first match over a late one.
+                score -= ti as i64;
+            }
+            positions.push(ti);
+            prev_match = Some(ti);
+            qi += 1;
+        }
+    }
+
+    if qi < query.len() {
+        return None;
+    }
+
+    Some((score, positions))
+}
+
+/// Filter and rank `items` (as `(index, text)` pairs) against `query`,
+/// returning `(index, matched_positions)` sorted best match first. An empty
+/// query matches everything in its original order.
+pub fn fuzzy_filter<'a, I, T>(items: I, query: &str) -> Vec<(usize, Vec<usize>)>
+where
+    I: IntoIterator<Item = (usize, T)>,
+    T: AsRef<str> + 'a,
+{
+    let mut matches: Vec<(usize, i64, Vec<usize>)> = items
+        .into_iter()
+        .filter_map(|(idx, text)| {
+            fuzzy_match(query, text.as_ref()).map(|(score, positions)| (idx, score, positions))
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    matches.into_iter().map(|(idx, _, positions)| (idx, positions)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_subsequence() {
+        let (_, positions) = fuzzy_match("pdf", "pdf-editor").unwrap();
+        assert_eq!(positions, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_fuzzy_match_rejects_out_of_order() {
+        assert!(fuzzy_match("fdp", "pdf-editor").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_filter_ranks_contiguous_match_higher() {
+        // Both match "pdf" starting at index 0; only "pdf-editor" matches it
+        // contiguously.
+        let items = vec![(0, "prod-config"), (1, "pdf-editor")];
+        let ranked = fuzzy_filter(items, "pdf");
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].0, 1);
+    }
+}
@@ -1,6 +1,8 @@
 //! Interactive TUI for skills management
 
 mod app;
+mod fuzzy;
+pub mod picker;
 mod ui;
 
 use anyhow::Result;
@@ -82,7 +84,7 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Resul
                         KeyCode::Char('a') => app.state = AppState::AddMarketplace,
                         KeyCode::Up | KeyCode::Char('k') => app.previous_marketplace(),
                         KeyCode::Down | KeyCode::Char('j') => app.next_marketplace(),
-                        KeyCode::Enter => app.enter_marketplace(),
+                        KeyCode::Enter => app.enter_marketplace().await?,
                         KeyCode::Char('d') | KeyCode::Delete => app.remove_selected_marketplace().await?,
                         _ => {}
                     },
@@ -100,11 +102,20 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Resul
                         }
                         _ => {}
                     },
+                    AppState::MarketplaceSkills if app.marketplace_skill_filtering => match key.code {
+                        KeyCode::Esc | KeyCode::Enter => app.exit_skill_filter(),
+                        KeyCode::Up => app.previous_marketplace_skill(),
+                        KeyCode::Down => app.next_marketplace_skill(),
+                        KeyCode::Backspace => app.pop_skill_filter_char(),
+                        KeyCode::Char(c) => app.push_skill_filter_char(c),
+                        _ => {}
+                    },
                     AppState::MarketplaceSkills => match key.code {
                         KeyCode::Esc => app.state = AppState::Marketplaces,
                         KeyCode::Up | KeyCode::Char('k') => app.previous_marketplace_skill(),
                         KeyCode::Down | KeyCode::Char('j') => app.next_marketplace_skill(),
                         KeyCode::Enter | KeyCode::Char(' ') => app.toggle_marketplace_skill().await?,
+                        KeyCode::Char('/') => app.enter_skill_filter(),
                         _ => {}
                     },
                 }
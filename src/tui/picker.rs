@@ -0,0 +1,162 @@
+//! Standalone fuzzy multi-select picker
+//!
+//! Reuses the crate's ratatui/crossterm stack outside of the main `App`
+//! loop so commands like `add` can offer an interactive picker without
+//! dragging in the whole TUI state machine.
+
+use anyhow::Result;
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use std::collections::HashSet;
+use std::io;
+
+/// An entry offered to the user in the picker.
+pub struct PickerItem {
+    pub name: String,
+    pub description: String,
+}
+
+/// Render a full-screen, incrementally-filterable multi-select list over
+/// `items` and return the indices the user picked. Space/Enter toggle and
+/// confirm a selection; Esc cancels and returns an empty list.
+pub fn multi_select(items: &[PickerItem]) -> Result<Vec<usize>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let result = run_picker(&mut terminal, items);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn run_picker<B: Backend>(terminal: &mut Terminal<B>, items: &[PickerItem]) -> Result<Vec<usize>> {
+    let mut query = String::new();
+    let mut cursor = 0usize;
+    let mut selected: HashSet<usize> = HashSet::new();
+
+    loop {
+        let filtered = filter(items, &query);
+        if cursor >= filtered.len() {
+            cursor = filtered.len().saturating_sub(1);
+        }
+
+        terminal.draw(|f| draw(f, items, &filtered, &query, cursor, &selected))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Esc => return Ok(Vec::new()),
+                KeyCode::Enter => {
+                    if selected.is_empty() {
+                        if let Some(&(idx, _)) = filtered.get(cursor) {
+                            selected.insert(idx);
+                        }
+                    }
+                    let mut result: Vec<usize> = selected.into_iter().collect();
+                    result.sort_unstable();
+                    return Ok(result);
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(&(idx, _)) = filtered.get(cursor) {
+                        if !selected.insert(idx) {
+                            selected.remove(&idx);
+                        }
+                    }
+                }
+                KeyCode::Up => cursor = cursor.saturating_sub(1),
+                KeyCode::Down => {
+                    if cursor + 1 < filtered.len() {
+                        cursor += 1;
+                    }
+                }
+                KeyCode::Backspace => {
+                    query.pop();
+                    cursor = 0;
+                }
+                KeyCode::Char(c) => {
+                    query.push(c);
+                    cursor = 0;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Case-insensitive fuzzy (subsequence) filter over name + description,
+/// keeping the items' original order.
+fn filter<'a>(items: &'a [PickerItem], query: &str) -> Vec<(usize, &'a PickerItem)> {
+    if query.is_empty() {
+        return items.iter().enumerate().collect();
+    }
+    items.iter().enumerate()
+        .filter(|(_, item)| {
+            super::fuzzy::fuzzy_match(query, &item.name).is_some()
+                || super::fuzzy::fuzzy_match(query, &item.description).is_some()
+        })
+        .collect()
+}
+
+fn draw(
+    frame: &mut Frame,
+    all_items: &[PickerItem],
+    filtered: &[(usize, &PickerItem)],
+    query: &str,
+    cursor: usize,
+    selected: &HashSet<usize>,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    let input = Paragraph::new(query)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().title("Filter").borders(Borders::ALL));
+    frame.render_widget(input, chunks[0]);
+
+    let list_items: Vec<ListItem> = filtered.iter().enumerate()
+        .map(|(row, (idx, item))| {
+            let checkbox = if selected.contains(idx) { "[x]" } else { "[ ]" };
+            let text = format!("{} {} - {}", checkbox, item.name, item.description);
+
+            let style = if row == cursor {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else if selected.contains(idx) {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::White)
+            };
+
+            ListItem::new(text).style(style)
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .block(Block::default()
+            .title(format!("Skills ({}/{})", filtered.len(), all_items.len()))
+            .borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_widget(list, chunks[1]);
+
+    let help = Paragraph::new("type to filter  space:toggle  enter:confirm  esc:cancel")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, chunks[2]);
+}
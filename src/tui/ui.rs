@@ -38,7 +38,13 @@ pub fn draw(frame: &mut Frame, app: &App) {
         AppState::AddSkill => "Enter:confirm  Esc:cancel",
         AppState::Marketplaces => "a:add  Enter:browse  d:delete  Esc:back  j/k:navigate",
         AppState::AddMarketplace => "Enter:confirm  Esc:cancel",
-        AppState::MarketplaceSkills => "Space/Enter:toggle  Esc:back  j/k:navigate",
+        AppState::MarketplaceSkills => {
+            if app.marketplace_skill_filtering {
+                "Esc/Enter:stop searching  type to filter"
+            } else {
+                "Space/Enter:toggle  /:search  Esc:back  j/k:navigate"
+            }
+        }
     };
     
     let status_text = if app.status.is_empty() {
@@ -183,9 +189,9 @@ fn draw_marketplace_skills(frame: &mut Frame, app: &App, area: Rect) {
     let marketplace_name = app.current_marketplace.as_deref().unwrap_or("Unknown");
     let marketplace = app.config.marketplaces.iter()
         .find(|m| Some(m.name.as_str()) == app.current_marketplace.as_deref());
-    
+
     if app.marketplace_available_skills.is_empty() {
-        let empty = Paragraph::new("No skills discovered yet.\n\nRun 'skills update' to fetch available skills.")
+        let empty = Paragraph::new("No skills discovered in this marketplace.")
             .style(Style::default().fg(Color::DarkGray))
             .block(Block::default()
                 .title(format!("Skills in {}", marketplace_name))
@@ -193,35 +199,78 @@ fn draw_marketplace_skills(frame: &mut Frame, app: &App, area: Rect) {
         frame.render_widget(empty, area);
         return;
     }
-    
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let filter_title = if app.marketplace_skill_filtering {
+        "Search (Esc/Enter to stop)"
+    } else {
+        "Search (/ to filter)"
+    };
+    let filter = Paragraph::new(app.marketplace_skill_query.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().title(filter_title).borders(Borders::ALL));
+    frame.render_widget(filter, chunks[0]);
+
+    if app.marketplace_skill_filtering {
+        frame.set_cursor_position(Position::new(
+            chunks[0].x + app.marketplace_skill_query.len() as u16 + 1,
+            chunks[0].y + 1,
+        ));
+    }
+
     let enabled: std::collections::HashSet<_> = marketplace
         .map(|m| m.enabled.iter().collect())
         .unwrap_or_default();
-    
-    let items: Vec<ListItem> = app.marketplace_available_skills
+
+    let filtered = app.filtered_marketplace_skills();
+
+    let items: Vec<ListItem> = filtered
         .iter()
         .enumerate()
-        .map(|(i, name)| {
+        .map(|(row, (idx, positions))| {
+            let name = &app.marketplace_available_skills[*idx];
             let is_enabled = enabled.contains(name);
-            let checkbox = if is_enabled { "[x]" } else { "[ ]" };
-            let text = format!("{} {}", checkbox, name);
-            
-            let style = if i == app.selected_marketplace_skill {
+            let checkbox = if is_enabled { "[x] " } else { "[ ] " };
+
+            let base_style = if row == app.selected_marketplace_skill {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
             } else if is_enabled {
                 Style::default().fg(Color::Green)
             } else {
                 Style::default().fg(Color::White)
             };
-            
-            ListItem::new(text).style(style)
+
+            let mut spans = vec![Span::styled(checkbox, base_style)];
+            for (ci, ch) in name.chars().enumerate() {
+                let style = if positions.contains(&ci) {
+                    base_style.fg(Color::Magenta).add_modifier(Modifier::UNDERLINED)
+                } else {
+                    base_style
+                };
+                spans.push(Span::styled(ch.to_string(), style));
+            }
+            if let Some(desc) = app.marketplace_skill_descriptions.get(name) {
+                spans.push(Span::styled(format!(" - {}", desc), Style::default().fg(Color::DarkGray)));
+            }
+
+            ListItem::new(Line::from(spans))
         })
         .collect();
-    
+
     let list = List::new(items)
         .block(Block::default()
-            .title(format!("Skills in {}", marketplace_name))
+            .title(format!(
+                "Skills in {} ({}/{})",
+                marketplace_name, filtered.len(), app.marketplace_available_skills.len()
+            ))
             .borders(Borders::ALL));
-    
-    frame.render_widget(list, area);
+
+    frame.render_widget(list, chunks[1]);
 }